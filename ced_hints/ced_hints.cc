@@ -0,0 +1,40 @@
+// Adds compact_enc_det_detect_with_hints to the libced.a built from the
+// ffi branch of compact_enc_det. See README.md for how to build it.
+
+#include <stddef.h>
+#include <string.h>
+
+#include <string>
+
+#include "compact_enc_det/compact_enc_det.h"
+#include "util/encodings/encodings.h"
+#include "util/languages/languages.h"
+
+// Like compact_enc_det_detect but passes `tld` (without the dot) as the
+// TLD of the URL hint and `lang` as the language hint and reports CED's
+// is_reliable. An empty hint means no hint.
+extern "C" const char* compact_enc_det_detect_with_hints(
+    const char* text, size_t text_len, const char* tld, size_t tld_len,
+    const char* lang, size_t lang_len, size_t* name_len, bool* is_reliable) {
+  std::string url;
+  if (tld_len) {
+    url = "http://example.";
+    url.append(tld, tld_len);
+    url.append("/");
+  }
+  Language language = UNKNOWN_LANGUAGE;
+  if (lang_len) {
+    std::string code(lang, lang_len);
+    if (!LanguageFromCode(code.c_str(), &language)) {
+      language = UNKNOWN_LANGUAGE;
+    }
+  }
+  int bytes_consumed = 0;
+  Encoding encoding = CompactEncDet::DetectEncoding(
+      text, static_cast<int>(text_len), tld_len ? url.c_str() : NULL, NULL,
+      NULL, UNKNOWN_ENCODING, language, CompactEncDet::WEB_CORPUS, false,
+      &bytes_consumed, is_reliable);
+  const char* name = MimeEncodingName(encoding);
+  *name_len = strlen(name);
+  return name;
+}
@@ -0,0 +1,21 @@
+#!/usr/bin/env python3
+# A stand-in external detector for testing the protocol of `--external`.
+# Guesses UTF-8 if the sample is valid UTF-8 and windows-1252 otherwise.
+
+import sys
+
+stdin = sys.stdin.buffer
+stdout = sys.stdout
+
+while True:
+    header = stdin.readline()
+    if not header:
+        break
+    length, tld, lang = header.rstrip(b"\n").split(b"\t")
+    sample = stdin.read(int(length))
+    try:
+        sample.decode("utf-8")
+        stdout.write("UTF-8\t1.0\n")
+    except UnicodeDecodeError:
+        stdout.write("windows-1252\t0.5\n")
+    stdout.flush()